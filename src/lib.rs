@@ -6,42 +6,58 @@
 //! ```rust, no_run
 //! use watchrs::Watcher;
 //!
+//! # async fn run() {
 //! // First create and subscribe to a topic
 //! let watcher = Watcher::default();
-//! watcher
+//! let (topic_arn, _) = watcher
 //!     .subscribe("michaelhabib1868@gmail.com".to_owned(), None)
-//!     .and_then(|(topic_arn, _)| {
-//!         watcher
-//!             .create_job_watcher_rule(
-//!                 "my_batch_job_rule".to_owned(),
-//!                 // enable?
-//!                 true,
-//!                 Some("watch failed jobs".to_owned()),
-//!                 Some(vec!["FAILED".to_owned(), "RUNNABLE".to_owned()]),
-//!                 Some(vec!["JOB_QUEUE_ARN".to_owned()]),
-//!                 Some(vec!["JOB_DEFINITION_NAME".to_owned()])
-//!             )
-//!             .map(|rule_name| (topic_arn, rule_name))
-//!     })
-//!       .and_then(|(topic_arn, rule_name)| {
-//!            // create target
-//!            watcher.create_sns_target(rule_name, topic_arn)
-//!     })
-//!     .expect("failed to create alerting system");
+//!     .await
+//!     .expect("failed to subscribe");
+//! let rule_name = watcher
+//!     .create_job_watcher_rule(
+//!         "my_batch_job_rule".to_owned(),
+//!         // enable?
+//!         true,
+//!         Some("watch failed jobs".to_owned()),
+//!         Some(vec!["FAILED".to_owned(), "RUNNABLE".to_owned()]),
+//!         Some(vec!["JOB_QUEUE_ARN".to_owned()]),
+//!         Some(vec!["JOB_DEFINITION_NAME".to_owned()]),
+//!     )
+//!     .await
+//!     .expect("failed to create rule");
+//! // create target
+//! watcher
+//!     .create_sns_target(rule_name, topic_arn)
+//!     .await
+//!     .expect("failed to create target");
+//! # }
+//! ```
 #![deny(missing_docs)]
 
 use log::{error, info};
+use rusoto_batch::{Batch, BatchClient, CancelJobRequest, TerminateJobRequest};
 use rusoto_core::region::Region;
 use rusoto_events::{
-    CloudWatchEvents, CloudWatchEventsClient, PutRuleRequest, PutTargetsRequest, Target,
+    CloudWatchEvents, CloudWatchEventsClient, InputTransformer as EventInputTransformer,
+    PutRuleRequest, PutTargetsRequest, Tag, Target,
 };
 use rusoto_sns::{
-    CreateTopicInput, DeleteTopicInput, Sns, SnsClient, SubscribeInput, UnsubscribeInput,
+    CreateTopicInput, DeleteTopicInput, ListSubscriptionsByTopicInput, Sns, SnsClient,
+    SubscribeInput, UnsubscribeInput,
+};
+use rusoto_sqs::{
+    CreateQueueRequest, DeleteMessageRequest, GetQueueAttributesRequest, ReceiveMessageRequest,
+    SetQueueAttributesRequest, Sqs, SqsClient,
 };
 use std::collections::HashMap;
+#[cfg(feature = "tokio")]
+use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
 
 use chrono::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "tokio")]
+use tokio::sync::mpsc::{channel, error::TrySendError, Receiver, Sender};
 
 /// An enum whos varients describe the point of faliure during AWS calls.
 /// For now, the value captured by the enum only contains a short description of the error.
@@ -55,6 +71,282 @@ pub enum WatchError {
     EventRule(String),
     /// Indicates a failure when creating or deleting a event target
     EventTarget(String),
+    /// Indicates a failure while creating, polling, or deleting messages from an SQS queue
+    SQSQueue(String),
+    /// Indicates a failure while cancelling or terminating a Batch job
+    Batch(String),
+}
+
+/// Describes when [`Watcher::auto_remediate`] should act on a watched [`BatchJobEvent`].
+///
+/// The `reason` is attached to the cancelled/terminated job and recorded in the Batch activity
+/// logs. `RUNNABLE` jobs created at least `max_runnable_age_secs` ago are cancelled, and jobs
+/// whose status reason contains `terminate_status_reason_pattern` are terminated.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct RemediationPolicy {
+    reason: String,
+    max_runnable_age_secs: Option<i64>,
+    terminate_status_reason_pattern: Option<String>,
+}
+
+impl RemediationPolicy {
+    /// Creates a policy that takes no action until a rule is added. `reason` is attached to every
+    /// job this policy acts on.
+    pub fn new(reason: String) -> Self {
+        RemediationPolicy {
+            reason,
+            max_runnable_age_secs: None,
+            terminate_status_reason_pattern: None,
+        }
+    }
+
+    /// Cancel `RUNNABLE` jobs whose `createdAt` is at least `secs` seconds in the past.
+    pub fn cancel_runnable_after(mut self, secs: i64) -> Self {
+        self.max_runnable_age_secs = Some(secs);
+        self
+    }
+
+    /// Terminate jobs whose status reason contains `pattern`.
+    pub fn terminate_matching(mut self, pattern: String) -> Self {
+        self.terminate_status_reason_pattern = Some(pattern);
+        self
+    }
+}
+
+/// A subset of the `detail` field of an AWS Batch "Job State Change" event.
+///
+/// Unlike [`BatchRuleDetails`], which is serialized *into* a rule expression, this struct is
+/// deserialized *out* of the event payload delivered to a target so callers can consume state
+/// changes programmatically rather than only over SNS email. The full event shape is documented
+/// in the [AWS Documentation](https://docs.aws.amazon.com/batch/latest/userguide/batch_cwe_events.html).
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct BatchJobEvent {
+    /// The name the job was submitted with.
+    #[serde(rename = "jobName")]
+    pub job_name: String,
+    /// The id AWS Batch assigned to the job.
+    #[serde(rename = "jobId")]
+    pub job_id: String,
+    /// The arn of the job queue the job was submitted to.
+    #[serde(rename = "jobQueue")]
+    pub job_queue: String,
+    /// The job's current status, e.g. `RUNNABLE`, `RUNNING`, `FAILED`.
+    pub status: String,
+    /// A short, human readable reason attached to the status, when present.
+    #[serde(rename = "statusReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_reason: Option<String>,
+    /// The unix timestamp, in milliseconds, the job was created at.
+    #[serde(rename = "createdAt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<i64>,
+    /// The arn of the job definition the job was submitted with.
+    #[serde(rename = "jobDefinition")]
+    pub job_definition: String,
+}
+
+/// The CloudWatch Events envelope wrapping a Batch job [`BatchJobEvent`] in its `detail` field.
+#[derive(Debug, Deserialize)]
+struct BatchJobEventEnvelope {
+    detail: BatchJobEvent,
+}
+
+/// An enum whos varients describe failures while fanning events out to in-process subscribers.
+///
+/// These are kept separate from [`WatchError`], which covers the AWS resource-setup calls, since
+/// the event router is a purely local concern.
+#[cfg(feature = "tokio")]
+#[derive(Eq, PartialEq, Debug)]
+pub enum WatchEventError {
+    /// The subscriber registry lock was poisoned by a panic in another thread.
+    LockPoisoned,
+}
+
+/// A per-subscriber predicate evaluated against a [`BatchJobEvent`] before it is forwarded.
+///
+/// An empty filter (the [`Default`]) matches every event. Each set field narrows the match:
+/// the event's status must be in `statuses`, its job queue must equal `job_queue`, and its job
+/// name must start with `job_name_prefix`.
+///
+/// ```rust, no_run
+/// # use watchrs::Filter;
+/// let filter = Filter::default()
+///     .statuses(vec!["FAILED".to_owned()])
+///     .job_name_prefix("nightly-".to_owned());
+/// ```
+#[cfg(feature = "tokio")]
+#[derive(Clone, Default, Debug)]
+pub struct Filter {
+    statuses: Option<HashSet<String>>,
+    job_queue: Option<String>,
+    job_name_prefix: Option<String>,
+}
+
+#[cfg(feature = "tokio")]
+impl Filter {
+    /// Only forward events whose status is one of `statuses`.
+    pub fn statuses(mut self, statuses: Vec<String>) -> Self {
+        self.statuses = Some(statuses.into_iter().collect());
+        self
+    }
+
+    /// Only forward events whose job queue arn equals `job_queue`.
+    pub fn job_queue(mut self, job_queue: String) -> Self {
+        self.job_queue = Some(job_queue);
+        self
+    }
+
+    /// Only forward events whose job name begins with `job_name_prefix`.
+    pub fn job_name_prefix(mut self, job_name_prefix: String) -> Self {
+        self.job_name_prefix = Some(job_name_prefix);
+        self
+    }
+
+    /// Returns whether `event` satisfies every set field of this filter.
+    fn matches(&self, event: &BatchJobEvent) -> bool {
+        if let Some(statuses) = &self.statuses {
+            if !statuses.contains(&event.status) {
+                return false;
+            }
+        }
+        if let Some(job_queue) = &self.job_queue {
+            if &event.job_queue != job_queue {
+                return false;
+            }
+        }
+        if let Some(prefix) = &self.job_name_prefix {
+            if !event.job_name.starts_with(prefix) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// A registered subscriber: the [`Filter`] it asked for and the sender feeding its stream.
+#[cfg(feature = "tokio")]
+struct Subscriber {
+    filter: Filter,
+    sender: Sender<BatchJobEvent>,
+}
+
+/// The set of in-process subscribers an event is fanned out to.
+#[cfg(feature = "tokio")]
+#[derive(Default)]
+struct Watchers {
+    subscribers: Vec<Subscriber>,
+}
+
+/// The kind of resource a Cloudwatch Event Target delivers matched events to.
+///
+/// Each variant carries the arn of the underlying resource. Use it with
+/// [`Watcher::create_target`] to drive an SNS topic, a Lambda remediation function, or an SQS
+/// queue from a single rule.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum TargetKind {
+    /// An SNS topic, identified by its arn.
+    Sns(String),
+    /// A Lambda function, identified by its arn.
+    Lambda(String),
+    /// An SQS queue, identified by its arn.
+    Sqs(String),
+}
+
+impl TargetKind {
+    /// The arn of the underlying resource.
+    fn arn(&self) -> &str {
+        match self {
+            TargetKind::Sns(arn) | TargetKind::Lambda(arn) | TargetKind::Sqs(arn) => arn,
+        }
+    }
+
+    /// A short label used when naming the target.
+    fn label(&self) -> &'static str {
+        match self {
+            TargetKind::Sns(_) => "sns",
+            TargetKind::Lambda(_) => "lambda",
+            TargetKind::Sqs(_) => "sqs",
+        }
+    }
+}
+
+/// Reformats the raw Batch event before it is delivered to a target.
+///
+/// `input_paths_map` maps a name to a JSONPath into the event (e.g. `"job" -> "$.detail.jobName"`,
+/// `"state" -> "$.detail.status"`) and `input_template` is a template string referencing those
+/// names with `<name>` placeholders, yielding a human readable message instead of the full event
+/// JSON. See the [AWS Documentation](https://docs.aws.amazon.com/eventbridge/latest/userguide/eb-transform-target-input.html).
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct InputTransformer {
+    input_paths_map: HashMap<String, String>,
+    input_template: String,
+}
+
+impl InputTransformer {
+    /// Creates an `InputTransformer` from a map of named JSONPath keys and a template string.
+    ///
+    /// ```rust, no_run
+    /// # use std::collections::HashMap;
+    /// # use watchrs::InputTransformer;
+    /// let mut paths = HashMap::new();
+    /// paths.insert("job".to_owned(), "$.detail.jobName".to_owned());
+    /// paths.insert("state".to_owned(), "$.detail.status".to_owned());
+    /// let transformer = InputTransformer::new(paths, "\"<job> is now <state>\"".to_owned());
+    /// ```
+    pub fn new(input_paths_map: HashMap<String, String>, input_template: String) -> Self {
+        InputTransformer {
+            input_paths_map,
+            input_template,
+        }
+    }
+}
+
+impl From<InputTransformer> for EventInputTransformer {
+    fn from(transformer: InputTransformer) -> Self {
+        EventInputTransformer {
+            input_paths_map: Some(transformer.input_paths_map),
+            input_template: transformer.input_template,
+        }
+    }
+}
+
+/// Controls how much of the Batch event a rule's target forwards to its destination.
+///
+/// [`DetailType::Full`] (the default) forwards the raw event JSON unchanged.
+/// [`DetailType::Basic`] installs a summarizing [`InputTransformer`] on the rule's target so the
+/// destination receives a short human readable message instead of the full payload.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum DetailType {
+    /// Forward the raw Batch event JSON as delivered by CloudWatch Events.
+    Full,
+    /// Forward a summarized, human readable message built from the event's key fields.
+    Basic,
+}
+
+impl Default for DetailType {
+    fn default() -> Self {
+        DetailType::Full
+    }
+}
+
+impl DetailType {
+    /// The [`InputTransformer`] that realizes this detail level, or `None` for
+    /// [`DetailType::Full`], which forwards the event unchanged.
+    pub fn input_transformer(self) -> Option<InputTransformer> {
+        match self {
+            DetailType::Full => None,
+            DetailType::Basic => {
+                let mut paths = HashMap::new();
+                paths.insert("job".to_owned(), "$.detail.jobName".to_owned());
+                paths.insert("status".to_owned(), "$.detail.status".to_owned());
+                paths.insert("reason".to_owned(), "$.detail.statusReason".to_owned());
+                Some(InputTransformer::new(
+                    paths,
+                    "\"Batch job <job> is now <status> (<reason>)\"".to_owned(),
+                ))
+            }
+        }
+    }
 }
 
 /// Represents a subset of the details field an AWS Batch Event returns.
@@ -83,6 +375,154 @@ impl Default for BatchRuleDetails {
     }
 }
 
+/// A fluent builder for Batch job watcher rules, replacing the positional-argument
+/// [`Watcher::create_job_watcher_rule`] with a richer, more readable configuration surface.
+///
+/// `tags` and `client_request_token` are attached to the rule so it is discoverable and
+/// auditable. `detail_type` selects how much of the event a target created for this rule
+/// forwards (see [`DetailType`]); a [`DetailType::Basic`] rule records a summarizing
+/// [`InputTransformer`] that [`Watcher::create_target`] installs on the target.
+///
+/// ```rust, no_run
+/// # use watchrs::{DetailType, JobWatcherRuleBuilder, Watcher};
+/// # async fn run() {
+/// let watcher = Watcher::default();
+/// let rule_name = JobWatcherRuleBuilder::new("my_batch_job_rule".to_owned())
+///     .statuses(vec!["FAILED".to_owned()])
+///     .description("watch failed jobs".to_owned())
+///     .detail_type(DetailType::Basic)
+///     .build(&watcher)
+///     .await
+///     .unwrap();
+/// // the target picks up the summarizing transformer recorded by the builder.
+/// watcher
+///     .create_sns_target(rule_name, "watchrs_topic_arn".to_owned())
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct JobWatcherRuleBuilder {
+    rule_name: String,
+    enabled: bool,
+    description: Option<String>,
+    statuses: Option<Vec<String>>,
+    job_queues: Option<Vec<String>>,
+    job_names: Option<Vec<String>>,
+    detail_type: DetailType,
+    tags: Option<HashMap<String, String>>,
+    client_request_token: Option<String>,
+}
+
+impl JobWatcherRuleBuilder {
+    /// Starts a builder for a rule named `rule_name`. The rule is enabled unless overridden.
+    pub fn new(rule_name: String) -> Self {
+        JobWatcherRuleBuilder {
+            rule_name,
+            enabled: true,
+            description: None,
+            statuses: None,
+            job_queues: None,
+            job_names: None,
+            detail_type: DetailType::Full,
+            tags: None,
+            client_request_token: None,
+        }
+    }
+
+    /// Restricts the rule to jobs whose status is one of `statuses`.
+    pub fn statuses(mut self, statuses: Vec<String>) -> Self {
+        self.statuses = Some(statuses);
+        self
+    }
+
+    /// Restricts the rule to jobs submitted to one of `job_queues`.
+    pub fn job_queues(mut self, job_queues: Vec<String>) -> Self {
+        self.job_queues = Some(job_queues);
+        self
+    }
+
+    /// Restricts the rule to jobs whose name is one of `job_names`.
+    pub fn job_names(mut self, job_names: Vec<String>) -> Self {
+        self.job_names = Some(job_names);
+        self
+    }
+
+    /// Sets whether the rule is enabled on creation.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the rule's description.
+    pub fn description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Selects how much of the event a target created for this rule forwards. With
+    /// [`DetailType::Basic`], [`build`](JobWatcherRuleBuilder::build) records a summarizing
+    /// [`InputTransformer`] that [`Watcher::create_target`] installs on the rule's target.
+    pub fn detail_type(mut self, detail_type: DetailType) -> Self {
+        self.detail_type = detail_type;
+        self
+    }
+
+    /// Attaches tags to the rule for discoverability and auditing.
+    pub fn tags(mut self, tags: HashMap<String, String>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    /// Records a client request token on the rule, as a tag, for tracing a creation back to the
+    /// request that issued it.
+    pub fn client_request_token(mut self, client_request_token: String) -> Self {
+        self.client_request_token = Some(client_request_token);
+        self
+    }
+
+    /// Creates the rule on `watcher`, returning the rule name on success.
+    pub async fn build(self, watcher: &Watcher) -> Result<String, WatchError> {
+        let rule_details = BatchRuleDetails {
+            statuses: self.statuses,
+            job_queues: self.job_queues,
+            job_names: self.job_names,
+        };
+
+        // fold the client request token into the rule's tag set so it is recorded on the resource
+        // alongside any user supplied tags.
+        let mut tags = self.tags.unwrap_or_default();
+        if let Some(token) = self.client_request_token {
+            tags.insert("watchrs:client-request-token".to_owned(), token);
+        }
+        let tags = if tags.is_empty() {
+            None
+        } else {
+            Some(
+                tags.into_iter()
+                    .map(|(key, value)| Tag { key, value })
+                    .collect(),
+            )
+        };
+
+        // record the summarizing transformer, if any, so a target created for this rule forwards
+        // the detail level the caller asked for (see `Watcher::create_target`).
+        if let Some(transformer) = self.detail_type.input_transformer() {
+            watcher.record_rule_transformer(self.rule_name.clone(), transformer);
+        }
+
+        watcher
+            .put_watcher_rule(
+                self.rule_name,
+                self.enabled,
+                self.description,
+                rule_details,
+                tags,
+            )
+            .await
+    }
+}
+
 /// Used to create and operate on AWS resources related to monitoring Batch jobs.
 ///
 /// At the moment the `Watcher` struct only takes in a AWS region to indicate
@@ -90,41 +530,70 @@ impl Default for BatchRuleDetails {
 #[must_use]
 pub struct Watcher {
     region: Region,
+    /// Summarizing input transformers recorded by [`JobWatcherRuleBuilder`], keyed by rule name.
+    /// [`Watcher::create_target`] installs the entry for a rule on its target when the caller does
+    /// not pass a transformer of its own.
+    rule_transformers: Arc<RwLock<HashMap<String, InputTransformer>>>,
+    /// The registry of in-process subscribers fed by [`Watcher::dispatch`].
+    #[cfg(feature = "tokio")]
+    watchers: Arc<RwLock<Watchers>>,
 }
 
 impl Default for Watcher {
     fn default() -> Self {
         Watcher {
             region: Region::default(),
+            rule_transformers: Arc::new(RwLock::new(HashMap::new())),
+            #[cfg(feature = "tokio")]
+            watchers: Arc::new(RwLock::new(Watchers::default())),
         }
     }
 }
 
 impl Watcher {
+    /// Records the summarizing input transformer `JobWatcherRuleBuilder` selected for `rule_name`
+    /// so [`Watcher::create_target`] can install it when the rule's target is created.
+    fn record_rule_transformer(&self, rule_name: String, transformer: InputTransformer) {
+        if let Ok(mut transformers) = self.rule_transformers.write() {
+            transformers.insert(rule_name, transformer);
+        }
+    }
+
+    /// Returns the input transformer recorded for `rule_name`, if any.
+    fn rule_transformer(&self, rule_name: &str) -> Option<InputTransformer> {
+        self.rule_transformers
+            .read()
+            .ok()
+            .and_then(|transformers| transformers.get(rule_name).cloned())
+    }
+
     /// Creates a topic when `topic_arn is None and suscribes to it using the email provided.
     /// The method will skip the topic creation step whenever the `topic_arn` field `is_some()`,.
     /// `subscribe` will return back a tuple of the form (topic_arn, subscribtion_arn).
     ///
     /// ```rust,no_run
     /// # use watchrs::Watcher;
+    /// # async fn run() {
     /// let mut watcher = Watcher::default();
     /// let email = "emailtosubscribe@example.com".to_owned();
-    /// let (topic_arn, subscription_arn) = watcher.subscribe(email, None).unwrap();
+    /// let (topic_arn, subscription_arn) = watcher.subscribe(email, None).await.unwrap();
+    /// # }
     /// ```
-    pub fn subscribe(
+    pub async fn subscribe(
         &self,
         email: String,
         topic_arn: Option<String>,
     ) -> Result<(String, String), WatchError> {
-        // only create topic if needed
+        // only create topic if needed. The deterministic name keeps re-runs idempotent.
         let arn = if topic_arn.is_none() {
-            self.create_topic()?
+            self.create_or_get_topic("job-state-change", None).await?
         } else {
             topic_arn.expect("missing topic_arn")
         };
 
         // susbscribe to email and return (topic arn, subscribtion_arn)
         self.subscribe_email(arn.clone(), email)
+            .await
             .map(|subscription_arn| (arn, subscription_arn))
     }
 
@@ -134,11 +603,13 @@ impl Watcher {
     /// all of its subscriptions.
     /// ```rust, no_run
     /// # use watchrs::Watcher;
+    /// # async fn run() {
     /// let mut watcher = Watcher::default();
-    /// watcher.unsubscribe("validsubscriptionarn".to_owned(), false, None).unwrap();
+    /// watcher.unsubscribe("validsubscriptionarn".to_owned(), false, None).await.unwrap();
+    /// # }
     /// ```
     // TODO: Consider making this take less params
-    pub fn unsubscribe(
+    pub async fn unsubscribe(
         &self,
         subscription_arn: String,
         delete_topic: bool,
@@ -160,7 +631,7 @@ impl Watcher {
                 .delete_topic(DeleteTopicInput {
                     topic_arn: topic_arn.clone(),
                 })
-                .sync()
+                .await
                 .map_err(|err| {
                     error!("error deleting topic {}, err: {}", topic_arn, err);
                     WatchError::SNSSubscription(err.to_string())
@@ -170,7 +641,7 @@ impl Watcher {
                 .unsubscribe(UnsubscribeInput {
                     subscription_arn: subscription_arn.clone(),
                 })
-                .sync()
+                .await
                 .map_err(|err| {
                     error!(
                         "error unsubscribing from {}, err: {}",
@@ -197,6 +668,7 @@ impl Watcher {
     ///
     /// ```rust, no_run
     /// # use watchrs::Watcher;
+    /// # async fn run() {
     /// let mut watcher = Watcher::default();
     /// watcher.create_job_watcher_rule(
     ///     "my_batch_job_rule".to_owned(),
@@ -204,9 +676,10 @@ impl Watcher {
     ///     Some("watch failed jobs".to_owned()),
     ///     Some(vec!["FAILED".to_owned()]),
     ///     None,
-    ///     None).unwrap();
+    ///     None).await.unwrap();
+    /// # }
     /// ```
-    pub fn create_job_watcher_rule(
+    pub async fn create_job_watcher_rule(
         &self,
         rule_name: String,
         enable: bool,
@@ -215,24 +688,24 @@ impl Watcher {
         job_queues: Option<Vec<String>>,
         job_names: Option<Vec<String>>,
     ) -> Result<String, WatchError> {
-        let events_client = CloudWatchEventsClient::new(Region::default());
-        let enable_str = if enable { "ENABLED" } else { "DISABLED" };
-
         let rule_details = BatchRuleDetails {
             statuses,
             job_queues,
             job_names,
         };
 
-        let details_json = serde_json::to_string(&rule_details);
+        self.put_watcher_rule(rule_name, enable, rule_description, rule_details, None)
+            .await
+    }
 
-        if details_json.is_err() {
-            return Err(WatchError::EventRule(
-                "failed to serialize batch rule details".to_owned(),
-            ));
-        }
+    /// Builds the event pattern for a Batch job state change rule, embedding `rule_details` only
+    /// when at least one field is set, since AWS rejects empty fields.
+    fn batch_event_pattern(rule_details: &BatchRuleDetails) -> Result<String, WatchError> {
+        let details_json = serde_json::to_string(rule_details).map_err(|_| {
+            WatchError::EventRule("failed to serialize batch rule details".to_owned())
+        })?;
 
-        let mut event_pattern = r#"
+        let event_pattern = r#"
         "detail-type": [
             "Batch Job State Change"
         ],
@@ -243,19 +716,34 @@ impl Watcher {
         .to_owned();
 
         // only add the details str if its not empty. AWS does not allow empty fields.
-        if BatchRuleDetails::default() != rule_details {
-            event_pattern = format!(
+        if BatchRuleDetails::default() != *rule_details {
+            Ok(format!(
                 r#"{{
                     {},
                     "detail": {}
                     }}
             "#,
-                event_pattern,
-                details_json.expect("err with details json")
-            );
+                event_pattern, details_json
+            ))
         } else {
-            event_pattern = format!("{{{}}}", event_pattern);
+            Ok(format!("{{{}}}", event_pattern))
         }
+    }
+
+    /// Puts a Batch job state change rule, shared by [`Watcher::create_job_watcher_rule`] and
+    /// [`JobWatcherRuleBuilder::build`]. `tags` are attached to the rule for discoverability.
+    async fn put_watcher_rule(
+        &self,
+        rule_name: String,
+        enable: bool,
+        rule_description: Option<String>,
+        rule_details: BatchRuleDetails,
+        tags: Option<Vec<Tag>>,
+    ) -> Result<String, WatchError> {
+        let events_client = CloudWatchEventsClient::new(Region::default());
+        let enable_str = if enable { "ENABLED" } else { "DISABLED" };
+
+        let event_pattern = Self::batch_event_pattern(&rule_details)?;
 
         match events_client
             .put_rule(PutRuleRequest {
@@ -264,9 +752,51 @@ impl Watcher {
                 state: Some(enable_str.to_owned()),
                 event_pattern: Some(event_pattern),
                 role_arn: None,
+                tags,
+                ..PutRuleRequest::default()
+            })
+            .await
+        {
+            Ok(_) => {
+                info!("Succesfully put rule: {}", rule_name.clone());
+                Ok(rule_name)
+            }
+            Err(err) => {
+                error!("error putting rule: {}", err);
+                Err(WatchError::EventRule(err.to_string()))
+            }
+        }
+    }
+
+    /// Creates a Cloudwatch Event Rule driven by a `schedule_expression` (a cron or rate
+    /// expression) rather than an event pattern. This enables periodic sweeps, e.g. polling for
+    /// stuck jobs, instead of only reacting to Batch state changes.
+    ///
+    /// ```rust, no_run
+    /// # use watchrs::Watcher;
+    /// # async fn run() {
+    /// let watcher = Watcher::default();
+    /// watcher.create_scheduled_rule(
+    ///     "sweep_stuck_jobs".to_owned(),
+    ///     "rate(15 minutes)".to_owned(),
+    /// ).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn create_scheduled_rule(
+        &self,
+        rule_name: String,
+        schedule_expression: String,
+    ) -> Result<String, WatchError> {
+        let events_client = CloudWatchEventsClient::new(Region::default());
+
+        match events_client
+            .put_rule(PutRuleRequest {
+                name: rule_name.clone(),
+                state: Some("ENABLED".to_owned()),
+                schedule_expression: Some(schedule_expression),
                 ..PutRuleRequest::default()
             })
-            .sync()
+            .await
         {
             Ok(_) => {
                 info!("Succesfully put rule: {}", rule_name.clone());
@@ -284,36 +814,82 @@ impl Watcher {
     ///
     /// ```rust, no_run
     /// # use watchrs::Watcher;
+    /// # async fn run() {
     /// let mut watcher = Watcher::default();
     /// let subscription_arn = "validsubscriptionarn".to_owned();
     /// watcher.create_sns_target(
     ///     "my_batch_job_rule".to_owned(),
     ///     "watchrs_topic_arn".to_owned()
-    /// ).unwrap();
+    /// ).await.unwrap();
+    /// # }
     /// ```
-    pub fn create_sns_target(
+    pub async fn create_sns_target(
         &self,
         rule_name: String,
         topic_arn: String,
+    ) -> Result<(), WatchError> {
+        self.create_target(rule_name, TargetKind::Sns(topic_arn), None)
+            .await
+    }
+
+    /// Creates a Cloudwatch Event Target of the given [`TargetKind`] and attaches the rule
+    /// `rule_name` to it. A single rule can therefore drive an SNS topic, a Lambda remediation
+    /// function, or an SQS queue.
+    ///
+    /// When `input_transformer` is `Some`, the raw Batch event is reformatted before delivery,
+    /// for example into a human readable message rather than the full event JSON. When it is
+    /// `None`, a summarizing transformer recorded for the rule by [`JobWatcherRuleBuilder`]
+    /// (via [`DetailType::Basic`]) is installed instead, if one exists.
+    ///
+    /// ```rust, no_run
+    /// # use watchrs::{TargetKind, Watcher};
+    /// # async fn run() {
+    /// let watcher = Watcher::default();
+    /// watcher.create_target(
+    ///     "my_batch_job_rule".to_owned(),
+    ///     TargetKind::Lambda("arn:aws:lambda:us-east-1:123456789012:function:remediate".to_owned()),
+    ///     None,
+    /// ).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn create_target(
+        &self,
+        rule_name: String,
+        kind: TargetKind,
+        input_transformer: Option<InputTransformer>,
     ) -> Result<(), WatchError> {
         let events_client = CloudWatchEventsClient::new(Region::default());
 
+        // fall back to the summarizing transformer the rule's builder recorded, if the caller did
+        // not supply one explicitly.
+        let input_transformer =
+            input_transformer.or_else(|| self.rule_transformer(&rule_name));
+
         let now = Utc::now();
         let (year, month, day, hour) = (now.year(), now.month(), now.day(), now.hour());
-        let target_id = format!("watchrs_sns_target_{}_{}_{}_{}", year, month, day, hour);
+        let target_id = format!(
+            "watchrs_{}_target_{}_{}_{}_{}",
+            kind.label(),
+            year,
+            month,
+            day,
+            hour
+        );
+        let arn = kind.arn().to_owned();
 
-        let sns_target = Target {
+        let target = Target {
             id: target_id,
-            arn: topic_arn.clone(),
+            arn: arn.clone(),
+            input_transformer: input_transformer.map(Into::into),
             ..Target::default()
         };
 
         events_client
             .put_targets(PutTargetsRequest {
                 rule: rule_name.clone(),
-                targets: vec![sns_target],
+                targets: vec![target],
             })
-            .sync()
+            .await
             .map_err(|err| {
                 error!("error putting targets: {}", err);
                 WatchError::EventTarget(err.to_string())
@@ -329,13 +905,397 @@ impl Watcher {
                 } else {
                     info!(
                         "Succesfully put target with rule: {}, on {}",
-                        rule_name, topic_arn
+                        rule_name, arn
                     );
                     Ok(())
                 }
             })
     }
 
+    /// Creates a Cloudwatch Event Target pointed to the SQS queue with the provided arn.
+    /// The method will also attach the rule `rule_name` to the event target. This is the
+    /// queue-backed counterpart to [`Watcher::create_sns_target`]; use it when you want to
+    /// consume events off a queue with [`Watcher::poll_jobs`] rather than over SNS email.
+    ///
+    /// ```rust, no_run
+    /// # use watchrs::Watcher;
+    /// # async fn run() {
+    /// let mut watcher = Watcher::default();
+    /// watcher.create_sqs_target(
+    ///     "my_batch_job_rule".to_owned(),
+    ///     "arn:aws:sqs:us-east-1:123456789012:watchrs_jobs".to_owned()
+    /// ).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn create_sqs_target(
+        &self,
+        rule_name: String,
+        queue_arn: String,
+    ) -> Result<(), WatchError> {
+        self.create_target(rule_name, TargetKind::Sqs(queue_arn), None)
+            .await
+    }
+
+    /// Creates (idempotently) an SQS queue named `queue_name` and grants CloudWatch
+    /// Events/SNS permission to send messages to it, returning a tuple of the form
+    /// `(queue_url, queue_arn)`. `CreateQueue` returns the existing queue when the name
+    /// already matches, so re-running against an existing queue is safe.
+    ///
+    /// The returned arn can be handed straight to [`Watcher::create_sqs_target`] and the
+    /// url to [`Watcher::poll_jobs`].
+    ///
+    /// ```rust, no_run
+    /// # use watchrs::Watcher;
+    /// # async fn run() {
+    /// let mut watcher = Watcher::default();
+    /// let (queue_url, queue_arn) = watcher.create_queue("watchrs_jobs".to_owned()).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn create_queue(
+        &self,
+        queue_name: String,
+    ) -> Result<(String, String), WatchError> {
+        let sqs_client = SqsClient::new(Region::default());
+
+        let queue_url = sqs_client
+            .create_queue(CreateQueueRequest {
+                queue_name: queue_name.clone(),
+                ..CreateQueueRequest::default()
+            })
+            .await
+            .map_err(|err| {
+                error!("error creating queue: {}", err);
+                WatchError::SQSQueue(err.to_string())
+            })
+            .and_then(|resp| {
+                resp.queue_url.ok_or_else(|| {
+                    WatchError::SQSQueue("error retrieving queue url".to_owned())
+                })
+            })?;
+
+        // fetch the arn so we can scope the access policy to this queue.
+        let queue_arn = sqs_client
+            .get_queue_attributes(GetQueueAttributesRequest {
+                queue_url: queue_url.clone(),
+                attribute_names: Some(vec!["QueueArn".to_owned()]),
+            })
+            .await
+            .map_err(|err| {
+                error!("error retrieving queue attributes: {}", err);
+                WatchError::SQSQueue(err.to_string())
+            })
+            .and_then(|resp| {
+                resp.attributes
+                    .and_then(|mut attrs| attrs.remove("QueueArn"))
+                    .ok_or_else(|| WatchError::SQSQueue("error retrieving queue arn".to_owned()))
+            })?;
+
+        // allow CloudWatch Events and SNS to deliver messages to the queue. This mirrors the
+        // topic access policy set in `create_or_get_topic`.
+        let sqs_access_policy = format!(
+            r#"
+        {{
+            "Id": "AWSSQSCWEIntegration",
+            "Statement": [
+                {{
+                    "Sid": "SendEventsToSQS",
+                    "Effect": "Allow",
+                    "Principal": {{
+                        "Service": "events.amazonaws.com"
+                    }},
+                    "Action": "sqs:SendMessage",
+                    "Resource": "{0}"
+                }},
+                {{
+                    "Sid": "SendSNSToSQS",
+                    "Effect": "Allow",
+                    "Principal": {{
+                        "Service": "sns.amazonaws.com"
+                    }},
+                    "Action": "sqs:SendMessage",
+                    "Resource": "{0}"
+                }}
+            ],
+            "Version": "2008-10-17"
+        }}"#,
+            queue_arn
+        );
+
+        let mut attributes = HashMap::new();
+        attributes.insert("Policy".to_owned(), sqs_access_policy);
+
+        sqs_client
+            .set_queue_attributes(SetQueueAttributesRequest {
+                queue_url: queue_url.clone(),
+                attributes,
+            })
+            .await
+            .map_err(|err| {
+                error!("error setting queue attributes: {}", err);
+                WatchError::SQSQueue(err.to_string())
+            })
+            .map(|_| {
+                info!("Succesfully created queue {}", queue_arn);
+                (queue_url, queue_arn)
+            })
+    }
+
+    /// Long-polls the SQS queue at `queue_url`, deserializing each message body into a
+    /// [`BatchJobEvent`] and deleting the messages it successfully consumes. Messages whose
+    /// bodies fail to deserialize are left on the queue so they are not silently dropped.
+    ///
+    /// Call this in a loop to build a long-running monitor or dashboard off of Batch events
+    /// instead of relying on SNS email.
+    ///
+    /// ```rust, no_run
+    /// # use watchrs::Watcher;
+    /// # async fn run() {
+    /// let watcher = Watcher::default();
+    /// let events = watcher
+    ///     .poll_jobs("https://sqs.us-east-1.amazonaws.com/123456789012/watchrs_jobs".to_owned())
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn poll_jobs(&self, queue_url: String) -> Result<Vec<BatchJobEvent>, WatchError> {
+        let sqs_client = SqsClient::new(Region::default());
+        self.poll_jobs_with(&sqs_client, queue_url).await
+    }
+
+    /// The body of [`Watcher::poll_jobs`], generic over the SQS client so it can be exercised with
+    /// a stubbed client in tests.
+    async fn poll_jobs_with(
+        &self,
+        sqs_client: &impl Sqs,
+        queue_url: String,
+    ) -> Result<Vec<BatchJobEvent>, WatchError> {
+        let messages = sqs_client
+            .receive_message(ReceiveMessageRequest {
+                queue_url: queue_url.clone(),
+                max_number_of_messages: Some(10),
+                wait_time_seconds: Some(20),
+                ..ReceiveMessageRequest::default()
+            })
+            .await
+            .map_err(|err| {
+                error!("error receiving messages: {}", err);
+                WatchError::SQSQueue(err.to_string())
+            })?
+            .messages
+            .unwrap_or_default();
+
+        let mut events = Vec::with_capacity(messages.len());
+        for message in messages {
+            let body = match message.body {
+                Some(body) => body,
+                None => continue,
+            };
+
+            // the event arrives wrapped in the CloudWatch Events envelope, the job details we
+            // care about live in its `detail` field.
+            match serde_json::from_str::<BatchJobEventEnvelope>(&body) {
+                Ok(envelope) => {
+                    events.push(envelope.detail);
+                    if let Some(receipt_handle) = message.receipt_handle {
+                        sqs_client
+                            .delete_message(DeleteMessageRequest {
+                                queue_url: queue_url.clone(),
+                                receipt_handle,
+                            })
+                            .await
+                            .map_err(|err| {
+                                error!("error deleting message: {}", err);
+                                WatchError::SQSQueue(err.to_string())
+                            })?;
+                    }
+                }
+                Err(err) => {
+                    // leave the message on the queue rather than dropping events we cant read.
+                    error!("error deserializing message body: {}", err);
+                }
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Registers an in-process subscriber and returns the receiving half of its stream.
+    ///
+    /// Every [`BatchJobEvent`] passed to [`Watcher::dispatch`] that satisfies `filter` is pushed
+    /// onto the returned [`Receiver`]. Drop the receiver to unsubscribe; the sender is pruned the
+    /// next time an event matching its filter is dispatched. This turns `watchrs` into a live event router with
+    /// per-subscriber filtering rather than one coarse CloudWatch rule.
+    ///
+    /// ```rust, no_run
+    /// # use watchrs::{Filter, Watcher};
+    /// # async fn run() {
+    /// let watcher = Watcher::default();
+    /// let mut failed = watcher
+    ///     .subscribe_stream(Filter::default().statuses(vec!["FAILED".to_owned()]))
+    ///     .unwrap();
+    /// while let Some(event) = failed.recv().await {
+    ///     println!("{} failed", event.job_name);
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub fn subscribe_stream(
+        &self,
+        filter: Filter,
+    ) -> Result<Receiver<BatchJobEvent>, WatchEventError> {
+        let (sender, receiver) = channel(64);
+        let mut watchers = self
+            .watchers
+            .write()
+            .map_err(|_| WatchEventError::LockPoisoned)?;
+        watchers.subscribers.push(Subscriber { filter, sender });
+        Ok(receiver)
+    }
+
+    /// Fans `event` out to every registered subscriber whose [`Filter`] matches it, dropping the
+    /// event for any subscriber whose buffer is full and deregistering any whose receiver has been
+    /// dropped.
+    ///
+    /// The send is non-blocking: a single slow subscriber never stalls delivery to the others.
+    /// Feed this the events produced by [`Watcher::poll_jobs`] to drive the router.
+    #[cfg(feature = "tokio")]
+    pub async fn dispatch(&self, event: BatchJobEvent) -> Result<(), WatchEventError> {
+        let mut watchers = self
+            .watchers
+            .write()
+            .map_err(|_| WatchEventError::LockPoisoned)?;
+
+        watchers.subscribers.retain(|subscriber| {
+            if !subscriber.filter.matches(&event) {
+                // not a recipient for this event, but keep it registered.
+                return true;
+            }
+
+            match subscriber.sender.try_send(event.clone()) {
+                // delivered, or the buffer is full so we skip this event for the subscriber.
+                Ok(()) | Err(TrySendError::Full(_)) => true,
+                // the receiver was dropped, auto-deregister the subscriber.
+                Err(TrySendError::Closed(_)) => false,
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Cancels a Batch job that is still in the `SUBMITTED`, `PENDING`, or `RUNNABLE` state,
+    /// attaching `reason` to the job for the Batch activity logs. Returns the cancelled job id.
+    ///
+    /// ```rust, no_run
+    /// # use watchrs::Watcher;
+    /// # async fn run() {
+    /// let watcher = Watcher::default();
+    /// watcher.cancel_job("job-id".to_owned(), "stuck in queue".to_owned()).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn cancel_job(&self, job_id: String, reason: String) -> Result<String, WatchError> {
+        let batch_client = BatchClient::new(Region::default());
+
+        batch_client
+            .cancel_job(CancelJobRequest {
+                job_id: job_id.clone(),
+                reason,
+            })
+            .await
+            .map_err(|err| {
+                error!("error cancelling job {}: {}", job_id, err);
+                WatchError::Batch(err.to_string())
+            })
+            .map(|_| {
+                info!("Succesfully cancelled job {}", job_id);
+                job_id
+            })
+    }
+
+    /// Terminates a Batch job that has progressed to `STARTING` or `RUNNING`, attaching `reason`
+    /// to the job for the Batch activity logs. Returns the terminated job id.
+    ///
+    /// ```rust, no_run
+    /// # use watchrs::Watcher;
+    /// # async fn run() {
+    /// let watcher = Watcher::default();
+    /// watcher.terminate_job("job-id".to_owned(), "failed health check".to_owned()).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn terminate_job(
+        &self,
+        job_id: String,
+        reason: String,
+    ) -> Result<String, WatchError> {
+        let batch_client = BatchClient::new(Region::default());
+
+        batch_client
+            .terminate_job(TerminateJobRequest {
+                job_id: job_id.clone(),
+                reason,
+            })
+            .await
+            .map_err(|err| {
+                error!("error terminating job {}: {}", job_id, err);
+                WatchError::Batch(err.to_string())
+            })
+            .map(|_| {
+                info!("Succesfully terminated job {}", job_id);
+                job_id
+            })
+    }
+
+    /// Applies `policy` to a watched `event`, closing the loop between detecting a bad job state
+    /// and acting on it. Returns the cancelled/terminated job id when an action was taken, or
+    /// `None` when the event did not match the policy.
+    ///
+    /// Pair this with the event-stream subsystem to remediate jobs automatically instead of only
+    /// emailing a human.
+    ///
+    /// ```rust, no_run
+    /// # use watchrs::{RemediationPolicy, Watcher};
+    /// # async fn run() {
+    /// # let event = unimplemented!();
+    /// let watcher = Watcher::default();
+    /// let policy = RemediationPolicy::new("stuck job".to_owned()).cancel_runnable_after(3600);
+    /// watcher.auto_remediate(event, policy).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn auto_remediate(
+        &self,
+        event: &BatchJobEvent,
+        policy: RemediationPolicy,
+    ) -> Result<Option<String>, WatchError> {
+        let RemediationPolicy {
+            reason,
+            max_runnable_age_secs,
+            terminate_status_reason_pattern,
+        } = policy;
+
+        // cancel jobs that have been sitting in RUNNABLE past the configured age.
+        if event.status == "RUNNABLE" {
+            if let (Some(max_age), Some(created_at)) = (max_runnable_age_secs, event.created_at) {
+                let age_secs = (Utc::now().timestamp_millis() - created_at) / 1000;
+                if age_secs >= max_age {
+                    return self.cancel_job(event.job_id.clone(), reason).await.map(Some);
+                }
+            }
+        }
+
+        // terminate jobs whose failure reason matches the configured pattern.
+        if let (Some(pattern), Some(status_reason)) =
+            (terminate_status_reason_pattern, event.status_reason.as_ref())
+        {
+            if status_reason.contains(&pattern) {
+                return self
+                    .terminate_job(event.job_id.clone(), reason)
+                    .await
+                    .map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
     /// Sets the AWS region a `Watcher` instance should operate in. The default region
     /// is us-east-1. More information on supported services and AWS regions
     /// can be found [here](https://docs.aws.amazon.com/general/latest/gr/rande.html).
@@ -343,17 +1303,32 @@ impl Watcher {
         self.region = region
     }
 
-    /// creates a topic
-    fn create_topic(&self) -> Result<String, WatchError> {
+    /// Creates, or returns the arn of an existing, SNS topic using a deterministic name of the
+    /// form `{namespace}-watchrs-{event_type}` (the namespace is omitted when `None`).
+    ///
+    /// Because `CreateTopic` is idempotent in AWS - it returns the arn of the existing topic when
+    /// the name already matches - re-running this is safe in CI/deploy pipelines, and the stable
+    /// name lets multiple environments share a naming convention.
+    ///
+    /// ```rust, no_run
+    /// # use watchrs::Watcher;
+    /// # async fn run() {
+    /// let watcher = Watcher::default();
+    /// let topic_arn = watcher.create_or_get_topic("job-state-change", Some("prod")).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn create_or_get_topic(
+        &self,
+        event_type: &str,
+        namespace: Option<&str>,
+    ) -> Result<String, WatchError> {
         let sns_client = SnsClient::new(Region::default());
 
-        let now = Utc::now();
-        let (year, month, day, hour) = (now.year(), now.month(), now.day(), now.hour());
-
-        // discard everything after @
-        // add watchrs + date
-        // further limit to 256 characters for the topic name limit
-        let topic_name = &format!("watchrs_{}_{}_{}_{}", year, month, day, hour).to_owned();
+        // build a stable, namespaced name so repeat calls resolve to the same topic.
+        let topic_name = match namespace {
+            Some(namespace) => format!("{}-watchrs-{}", namespace, event_type),
+            None => format!("watchrs-{}", event_type),
+        };
         let mut attributes = HashMap::new();
         let sns_access_policy = format!(
             r#"
@@ -403,7 +1378,7 @@ impl Watcher {
                 attributes: Some(attributes),
                 name: topic_name.to_owned(),
             })
-            .sync()
+            .await
             .map_err(|err| {
                 error!("error creating topic: {:?}", err);
                 WatchError::SNSTopic(err.to_string())
@@ -418,9 +1393,21 @@ impl Watcher {
             })
     }
 
-    /// Subscribes the given email to a topic
-    fn subscribe_email(&self, topic_arn: String, email: String) -> Result<String, WatchError> {
+    /// Subscribes the given email to a topic, skipping the subscribe call when a confirmed
+    /// subscription for that endpoint already exists on the topic.
+    async fn subscribe_email(
+        &self,
+        topic_arn: String,
+        email: String,
+    ) -> Result<String, WatchError> {
         let sns_client = SnsClient::new(Region::default());
+
+        // don't re-subscribe an endpoint that's already attached to the topic.
+        if let Some(subscription_arn) = self.find_subscription(topic_arn.clone(), &email).await? {
+            info!("{} is already subscribed to {}", email, topic_arn);
+            return Ok(subscription_arn);
+        }
+
         let sub_input = SubscribeInput {
             protocol: "email".to_owned(),
             endpoint: Some(email.clone()),
@@ -429,7 +1416,7 @@ impl Watcher {
         };
         sns_client
             .subscribe(sub_input)
-            .sync()
+            .await
             .map_err(|err| {
                 error!("error creating topic: {}", err);
                 WatchError::SNSSubscription(err.to_string())
@@ -444,4 +1431,157 @@ impl Watcher {
                 }
             })
     }
+
+    /// Returns the subscription arn of an existing confirmed subscription for `endpoint` on the
+    /// topic, or `None` when the endpoint has no confirmed subscription. Pages through the topic's
+    /// subscriptions.
+    async fn find_subscription(
+        &self,
+        topic_arn: String,
+        endpoint: &str,
+    ) -> Result<Option<String>, WatchError> {
+        let sns_client = SnsClient::new(Region::default());
+        let mut next_token = None;
+
+        loop {
+            let resp = sns_client
+                .list_subscriptions_by_topic(ListSubscriptionsByTopicInput {
+                    topic_arn: topic_arn.clone(),
+                    next_token,
+                })
+                .await
+                .map_err(|err| {
+                    error!("error listing subscriptions for {}: {}", topic_arn, err);
+                    WatchError::SNSSubscription(err.to_string())
+                })?;
+
+            if let Some(subscriptions) = resp.subscriptions {
+                for subscription in subscriptions {
+                    if subscription.endpoint.as_deref() != Some(endpoint) {
+                        continue;
+                    }
+                    // an unconfirmed email subscription reports the literal "PendingConfirmation"
+                    // instead of an arn; skip it so we never hand that sentinel back as a real
+                    // subscription arn (a re-run will simply re-send the confirmation email).
+                    match subscription.subscription_arn.as_deref() {
+                        Some("PendingConfirmation") | None => continue,
+                        Some(_) => return Ok(subscription.subscription_arn),
+                    }
+                }
+            }
+
+            next_token = resp.next_token;
+            if next_token.is_none() {
+                return Ok(None);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_mock::{MockCredentialsProvider, MultipleMockRequestDispatcher};
+
+    #[test]
+    fn envelope_deserializes_job_details_from_detail_field() {
+        let body = r#"{
+            "detail-type": "Batch Job State Change",
+            "detail": {
+                "jobName": "nightly-report",
+                "jobId": "abc-123",
+                "jobQueue": "arn:aws:batch:us-east-1:123456789012:job-queue/reports",
+                "status": "FAILED",
+                "statusReason": "Essential container exited",
+                "createdAt": 1595203200000,
+                "jobDefinition": "arn:aws:batch:us-east-1:123456789012:job-definition/report:1"
+            }
+        }"#;
+
+        let envelope: BatchJobEventEnvelope = serde_json::from_str(body).unwrap();
+        let event = envelope.detail;
+        assert_eq!(event.job_name, "nightly-report");
+        assert_eq!(event.job_id, "abc-123");
+        assert_eq!(event.status, "FAILED");
+        assert_eq!(
+            event.status_reason.as_deref(),
+            Some("Essential container exited")
+        );
+        assert_eq!(event.created_at, Some(1595203200000));
+
+        // a round-trip back through the wire format preserves every field.
+        let reparsed: BatchJobEvent =
+            serde_json::from_str(&serde_json::to_string(&event).unwrap()).unwrap();
+        assert_eq!(reparsed, event);
+    }
+
+    #[tokio::test]
+    async fn poll_jobs_unwraps_envelope_and_deletes_message() {
+        let detail = r#"{"jobName":"event-test","jobId":"id-1","jobQueue":"queue","status":"RUNNING","jobDefinition":"def"}"#;
+        let body = format!(r#"{{"detail":{}}}"#, detail).replace('"', "&quot;");
+        let receive = format!(
+            r#"<ReceiveMessageResponse><ReceiveMessageResult><Message>
+                <MessageId>msg-1</MessageId>
+                <ReceiptHandle>receipt-1</ReceiptHandle>
+                <Body>{}</Body>
+            </Message></ReceiveMessageResult></ReceiveMessageResponse>"#,
+            body
+        );
+        let delete = r#"<DeleteMessageResponse><ResponseMetadata><RequestId>req-1</RequestId></ResponseMetadata></DeleteMessageResponse>"#;
+
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            rusoto_mock::MockRequestDispatcher::default().with_body(&receive),
+            rusoto_mock::MockRequestDispatcher::default().with_body(delete),
+        ]);
+        let sqs_client =
+            SqsClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+
+        let watcher = Watcher::default();
+        let events = watcher
+            .poll_jobs_with(&sqs_client, "https://example.com/queue".to_owned())
+            .await
+            .unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].job_name, "event-test");
+        assert_eq!(events[0].status, "RUNNING");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn filter_narrows_on_status_queue_and_prefix() {
+        let event = BatchJobEvent {
+            job_name: "nightly-report".to_owned(),
+            job_id: "id-1".to_owned(),
+            job_queue: "queue-a".to_owned(),
+            status: "FAILED".to_owned(),
+            status_reason: None,
+            created_at: None,
+            job_definition: "def".to_owned(),
+        };
+
+        // the empty filter matches everything.
+        assert!(Filter::default().matches(&event));
+
+        assert!(Filter::default()
+            .statuses(vec!["FAILED".to_owned()])
+            .matches(&event));
+        assert!(!Filter::default()
+            .statuses(vec!["SUCCEEDED".to_owned()])
+            .matches(&event));
+
+        assert!(Filter::default()
+            .job_queue("queue-a".to_owned())
+            .matches(&event));
+        assert!(!Filter::default()
+            .job_queue("queue-b".to_owned())
+            .matches(&event));
+
+        assert!(Filter::default()
+            .job_name_prefix("nightly-".to_owned())
+            .matches(&event));
+        assert!(!Filter::default()
+            .job_name_prefix("weekly-".to_owned())
+            .matches(&event));
+    }
 }